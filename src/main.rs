@@ -1,16 +1,83 @@
 use leptos::*;
 
+/// Shared, non-reactive application configuration.
+///
+/// Intentionally neither `Copy` nor `Clone`: it lives inside a `store_value`,
+/// which hands out a `Copy + 'static` handle so components can read it from
+/// anywhere without threading it down as props or paying signal re-render costs.
+struct Config {
+    /// Default maximum used when a `ProgressBar` is given no `max`.
+    default_max: u16,
+    /// Default fraction at which bars turn orange / red.
+    warn_at: f32,
+    danger_at: f32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            default_max: 100,
+            warn_at: 0.7,
+            danger_at: 0.9,
+        }
+    }
+}
+
 /// Shows progress towards a goal.
 #[component]
 fn ProgressBar(
     /// The maximum value of the progress bar.
-    #[prop(default = 100)] max: u16, // Optional, if not specified, default value is used
+    // MaybeSignal lets callers pass either a literal (`max=50`) or a signal, so
+    // `max` can change after mount just like `progress` does.
+    #[prop(into, optional)] max: Option<MaybeSignal<u16>>,
     /// How much progress should be displayed.
-    #[prop(into)] progress: Signal<i32> // Automatically calls .into() on the values passed
+    #[prop(into)] progress: Signal<i32>, // Automatically calls .into() on the values passed
     // Signal is an enumerated type: any kind of readable reactive signal.
     // MaybeSignal allows to use either static or reactive value.
+    /// When true, omit the `value` attribute entirely so the browser renders the
+    /// native "indeterminate" spinner state.
+    #[prop(optional)] indeterminate: MaybeSignal<bool>,
+    /// Fraction of `max` at which the bar turns orange.
+    #[prop(optional)] warn_at: Option<f32>,
+    /// Fraction of `max` at which the bar turns red.
+    #[prop(optional)] danger_at: Option<f32>,
 ) -> impl IntoView {
-    view! { <progress max=max value=progress /> }
+    // `max`, `warn_at` and `danger_at`, when omitted, fall back to the shared
+    // `Config` in context, or to `Config::default()` when no provider is present.
+    let config = use_context::<StoredValue<Config>>();
+    let cfg = |f: fn(&Config) -> f32| match config {
+        Some(c) => c.with_value(f),
+        None => f(&Config::default()),
+    };
+    let max: MaybeSignal<u16> = max.unwrap_or_else(|| {
+        config
+            .map(|c| c.with_value(|cfg| cfg.default_max))
+            .unwrap_or_else(|| Config::default().default_max)
+            .into()
+    });
+    let warn_at = warn_at.unwrap_or_else(|| cfg(|c| c.warn_at));
+    let danger_at = danger_at.unwrap_or_else(|| cfg(|c| c.danger_at));
+
+    // Derived signal: how full the bar is, as a fraction of its maximum. Reading
+    // `max`/`progress` inside the closure keeps the colour reactive to both.
+    let pct = move || progress.get() as f32 / max.get() as f32;
+    // The `value` attribute is bound through a closure so it can disappear
+    // completely while indeterminate, rather than always binding `value=progress`.
+    view! {
+        <progress
+            max=move || max.get()
+            value=move || (!indeterminate.get()).then(|| progress.get())
+            // Drive the fill colour from a CSS variable the stylesheet can pick up,
+            // using the same tuple form `App` uses for `--columns`.
+            style=("--bar-color", move || match pct() {
+                p if p >= danger_at => "red",
+                p if p >= warn_at => "orange",
+                _ => "green",
+            })
+            // Tag the bar once it reaches its goal so callers can style completion.
+            class:complete=move || pct() >= 1.0
+        />
+    }
 }
 
 /*
@@ -24,6 +91,35 @@ fn ProgressBar(
     define any side effects that run in response to those values changing, and describe UI. 
 */
 
+/// Creates a signal whose value is mirrored to `window.localStorage` under `key`.
+///
+/// On mount the stored string is read back and parsed; anything that goes wrong
+/// (storage unavailable in private browsing, missing key, unparsable value)
+/// falls back to `default`. An effect then writes the value back on every change,
+/// so both `count` and `x` get persistence without repeating the glue.
+fn persisted_signal(key: &str, default: i32) -> (ReadSignal<i32>, WriteSignal<i32>) {
+    // `local_storage()` returns `Result<Option<Storage>>`, both of which can be
+    // empty when storage is disabled, so collapse everything down to an Option.
+    let storage = window().local_storage().ok().flatten();
+
+    let initial = storage
+        .as_ref()
+        .and_then(|s| s.get_item(key).ok().flatten())
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default);
+
+    let (value, set_value) = create_signal(initial);
+
+    let key = key.to_string();
+    create_effect(move |_| {
+        if let Some(storage) = storage.as_ref() {
+            let _ = storage.set_item(&key, &value.get().to_string());
+        }
+    });
+
+    (value, set_value)
+}
+
 #[component]
 fn App() -> impl IntoView {
     /*  
@@ -33,10 +129,25 @@ fn App() -> impl IntoView {
         To set a current value: set_count.set(3) (overrides the value)
         In many cases, it is more efficient to use .with() or .update()
     */
-    let (count, set_count) = create_signal(0);
-    let (x, set_x) = create_signal(0);
+    // Share non-reactive defaults with every `ProgressBar` below without passing
+    // them as props. `store_value` keeps `Config` in the reactive ownership tree
+    // while handing back a `Copy` handle we can hand to `provide_context`.
+    provide_context(store_value(Config::default()));
+
+    let (count, set_count) = persisted_signal("count", 0);
+    let (x, set_x) = persisted_signal("x", 0);
     let double_count = move || count.get() * 2;
     let html = "<p>This HTML will be injected.</p>";
+
+    // A dynamic collection of independent progress bars. The `usize` is a stable
+    // key so the keyed `<For>` only touches the rows that actually change.
+    let (bars, set_bars) = create_signal::<Vec<(usize, RwSignal<i32>)>>(Vec::new());
+    let (next_id, set_next_id) = create_signal(0usize);
+    let add_bar = move |_| {
+        let id = next_id.get();
+        set_next_id.update(|n| *n += 1);
+        set_bars.update(|bars| bars.push((id, create_rw_signal(0))));
+    };
     /*
         defines user interfaces using a JSX-like format via the view macro.
      */
@@ -61,6 +172,31 @@ fn App() -> impl IntoView {
             <ProgressBar max=50 progress=Signal::derive(double_count) />
         </div>
 
+        <div>
+            <button on:click=add_bar>"Add Progress Bar"</button>
+            // Keyed list: adding or removing a bar only updates the affected nodes,
+            // and each row drives its own signal for fine-grained reactivity.
+            <For
+                each=move || bars.get()
+                key=|(id, _)| *id
+                children=move |(id, value)| {
+                    view! {
+                        <div>
+                            <button on:click=move |_| value.update(|n| *n += 10)>
+                                "+10"
+                            </button>
+                            <ProgressBar max=100 progress=value />
+                            <button on:click=move |_| {
+                                set_bars.update(|bars| bars.retain(|(bar_id, _)| *bar_id != id));
+                            }>
+                                "Remove"
+                            </button>
+                        </div>
+                    }
+                }
+            />
+        </div>
+
         <div inner_html=html />
 
         <div>